@@ -3,8 +3,9 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use std::{
-    ops::Deref,
-    time::{SystemTime, UNIX_EPOCH},
+    ops::{Add, AddAssign, Deref, Mul, Sub, SubAssign},
+    sync::OnceLock,
+    time::{Instant, SystemTime, UNIX_EPOCH},
 };
 
 pub const NANOSECOND: i64 = 1;
@@ -14,15 +15,169 @@ pub const SECOND: i64 = MILLISECOND * 1000;
 pub const MINUTE: i64 = SECOND * 60;
 pub const HOUR: i64 = MINUTE * 60;
 
-// Nanoseconds since the Unix epoch.
-#[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
-pub struct UnixNano(i64);
+// Mirrors nix's `TimeValLike`: shared constructors and accessors for all
+// four time types, so generic code can accept `impl TimeValLike` instead of
+// hardcoding one of them.
+pub trait TimeValLike: Sized {
+    fn hours(hours: i64) -> Self;
+    fn minutes(minutes: i64) -> Self;
+    fn seconds(seconds: i64) -> Self;
+    fn milliseconds(milliseconds: i64) -> Self;
+    fn microseconds(microseconds: i64) -> Self;
+    fn nanoseconds(nanoseconds: i64) -> Self;
 
-impl UnixNano {
-    #[must_use]
-    pub fn now() -> Self {
-        Self(
+    fn num_hours(&self) -> i64;
+    fn num_minutes(&self) -> i64;
+    fn num_seconds(&self) -> i64;
+    fn num_milliseconds(&self) -> i64;
+    fn num_microseconds(&self) -> i64;
+    fn num_nanoseconds(&self) -> i64;
+}
+
+// Multiplies `a` by `b`, saturating at `i64::MAX`/`i64::MIN` instead of
+// wrapping, so e.g. `TimeValLike::hours(i64::MAX)` doesn't silently wrap.
+fn checked_mul_saturating(a: i64, b: i64) -> i64 {
+    a.checked_mul(b)
+        .unwrap_or(if (a < 0) == (b < 0) { i64::MAX } else { i64::MIN })
+}
+
+// Implements `TimeValLike` for a nanosecond-native type (`UnixNano`,
+// `Duration`), where the raw `i64` already counts nanoseconds.
+macro_rules! impl_time_val_like_nanos {
+    ($t:ty) => {
+        impl TimeValLike for $t {
+            fn hours(hours: i64) -> Self {
+                Self(checked_mul_saturating(hours, HOUR))
+            }
+
+            fn minutes(minutes: i64) -> Self {
+                Self(checked_mul_saturating(minutes, MINUTE))
+            }
+
+            fn seconds(seconds: i64) -> Self {
+                Self(checked_mul_saturating(seconds, SECOND))
+            }
+
+            fn milliseconds(milliseconds: i64) -> Self {
+                Self(checked_mul_saturating(milliseconds, MILLISECOND))
+            }
+
+            fn microseconds(microseconds: i64) -> Self {
+                Self(checked_mul_saturating(microseconds, MICROSECOND))
+            }
+
+            fn nanoseconds(nanoseconds: i64) -> Self {
+                Self(nanoseconds)
+            }
+
+            fn num_hours(&self) -> i64 {
+                self.0 / HOUR
+            }
+
+            fn num_minutes(&self) -> i64 {
+                self.0 / MINUTE
+            }
+
+            fn num_seconds(&self) -> i64 {
+                self.0 / SECOND
+            }
+
+            fn num_milliseconds(&self) -> i64 {
+                self.0 / MILLISECOND
+            }
+
+            fn num_microseconds(&self) -> i64 {
+                self.0 / MICROSECOND
+            }
+
+            fn num_nanoseconds(&self) -> i64 {
+                self.0
+            }
+        }
+    };
+}
+
+// Implements `TimeValLike` for a 90kHz-native type (`UnixH264`,
+// `DurationH264`), where the raw `i64` counts H264 clock ticks and
+// sub-millisecond units need rescaling. `$num_nanoseconds` is the
+// `num_nanoseconds` body (receiving `$this` bound to `self`), since
+// `UnixH264::as_nanos()` returns `UnixNano` while `DurationH264::as_nanos()`
+// returns a raw `i64`.
+macro_rules! impl_time_val_like_h264 {
+    ($t:ty, $this:ident, $num_nanoseconds:expr) => {
+        impl TimeValLike for $t {
+            fn hours(hours: i64) -> Self {
+                Self(checked_mul_saturating(hours, H264_HOUR))
+            }
+
+            fn minutes(minutes: i64) -> Self {
+                Self(checked_mul_saturating(minutes, H264_MINUTE))
+            }
+
+            fn seconds(seconds: i64) -> Self {
+                Self(checked_mul_saturating(seconds, H264_SECOND))
+            }
+
+            fn milliseconds(milliseconds: i64) -> Self {
+                Self(checked_mul_saturating(milliseconds, H264_MILLISECOND))
+            }
+
+            fn microseconds(microseconds: i64) -> Self {
+                Self(rescale(microseconds, MICROSECOND_TIMESCALE, Timescale::new(H264_TIMESCALE)))
+            }
+
+            fn nanoseconds(nanoseconds: i64) -> Self {
+                Self(rescale(nanoseconds, NANOSECOND_TIMESCALE, Timescale::new(H264_TIMESCALE)))
+            }
+
+            fn num_hours(&self) -> i64 {
+                self.0 / H264_HOUR
+            }
+
+            fn num_minutes(&self) -> i64 {
+                self.0 / H264_MINUTE
+            }
+
+            fn num_seconds(&self) -> i64 {
+                self.0 / H264_SECOND
+            }
+
+            fn num_milliseconds(&self) -> i64 {
+                self.0 / H264_MILLISECOND
+            }
+
+            fn num_microseconds(&self) -> i64 {
+                rescale_saturating(
+                    self.0,
+                    Timescale::new(H264_TIMESCALE),
+                    MICROSECOND_TIMESCALE,
+                    Rounding::TowardZero,
+                )
+            }
+
+            fn num_nanoseconds(&self) -> i64 {
+                let $this = self;
+                $num_nanoseconds
+            }
+        }
+    };
+}
+
+// A source of the current time. Lets segment/frame PTS generation use a
+// monotonic clock while unit tests drive `now()` deterministically instead
+// of reading the real system time.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> UnixNano;
+}
+
+// Reads `SystemTime::now()` directly. Used for event timestamps, where
+// matching the wall clock matters more than monotonicity.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WallClock;
+
+impl Clock for WallClock {
+    fn now(&self) -> UnixNano {
+        UnixNano(
             i64::try_from(
                 SystemTime::now()
                     .duration_since(UNIX_EPOCH)
@@ -32,6 +187,50 @@ impl UnixNano {
             .expect("timestamp to fit i64"),
         )
     }
+}
+
+// Anchored once to a wall-clock reading at startup and thereafter advanced
+// by `Instant::elapsed()` deltas, so an NTP step or manual clock change
+// can't make timestamp generation go backwards or panic mid-capture.
+#[derive(Clone, Debug)]
+pub struct MonotonicClock {
+    anchor_unix: UnixNano,
+    anchor_instant: Instant,
+}
+
+impl MonotonicClock {
+    #[must_use]
+    pub fn new() -> Self {
+        Self { anchor_unix: UnixNano::now(), anchor_instant: Instant::now() }
+    }
+}
+
+impl Default for MonotonicClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MonotonicClock {
+    fn now(&self) -> UnixNano {
+        let elapsed = i64::try_from(self.anchor_instant.elapsed().as_nanos()).unwrap_or(i64::MAX);
+        self.anchor_unix.saturating_add_duration(Duration::from_nanos(elapsed))
+    }
+}
+
+// Process-wide monotonic clock backing `UnixH264::now()`.
+static MONOTONIC_CLOCK: OnceLock<MonotonicClock> = OnceLock::new();
+
+// Nanoseconds since the Unix epoch.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct UnixNano(i64);
+
+impl UnixNano {
+    #[must_use]
+    pub fn now() -> Self {
+        WallClock.now()
+    }
 
     #[must_use]
     pub fn add_duration(&self, duration: Duration) -> Option<Self> {
@@ -68,7 +267,50 @@ impl UnixNano {
         NaiveDateTime::from_timestamp_opt(sec, nanosec as u32)
     }
 
+    // Converts to the wire shape of `google.protobuf.Timestamp`, for
+    // exporting events and recording metadata over gRPC.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_proto(&self) -> Result<ProtoTimestamp, ProtoTimeError> {
+        let seconds = self.0.div_euclid(SECOND);
+        let nanos = self.0.rem_euclid(SECOND) as i32;
+        if !(PROTO_TIMESTAMP_SECONDS_MIN..=PROTO_TIMESTAMP_SECONDS_MAX).contains(&seconds) {
+            return Err(ProtoTimeError::SecondsOutOfRange);
+        }
+        Ok(ProtoTimestamp { seconds, nanos })
+    }
+
+    // Converts from the wire shape of `google.protobuf.Timestamp`. A
+    // timestamp can pass the documented protobuf range check (up to year
+    // 9999) and still not fit in `UnixNano`'s `i64` nanosecond count (which
+    // tops out around year 2262); that case reports
+    // `NotRepresentableAsNanos` rather than being conflated with
+    // `SecondsOutOfRange`.
+    pub fn from_proto(timestamp: ProtoTimestamp) -> Result<Self, ProtoTimeError> {
+        let (seconds, nanos) = normalize(timestamp.seconds, timestamp.nanos);
+        if !(PROTO_TIMESTAMP_SECONDS_MIN..=PROTO_TIMESTAMP_SECONDS_MAX).contains(&seconds) {
+            return Err(ProtoTimeError::SecondsOutOfRange);
+        }
+        seconds
+            .checked_mul(SECOND)
+            .and_then(|v| v.checked_add(i64::from(nanos)))
+            .map(Self)
+            .ok_or(ProtoTimeError::NotRepresentableAsNanos)
+    }
+
+    // Adds duration, clamping to `UnixNano::MAX` instead of overflowing.
+    #[must_use]
+    pub fn saturating_add_duration(&self, duration: Duration) -> Self {
+        Self(self.0.saturating_add(duration.0).max(0))
+    }
+
+    // Subtracts duration, clamping to zero instead of underflowing.
+    #[must_use]
+    pub fn saturating_sub_duration(&self, duration: Duration) -> Self {
+        Self(self.0.saturating_sub(duration.0).max(0))
+    }
+
     pub const MAX: UnixNano = UnixNano(i64::MAX);
+    pub const ZERO: UnixNano = UnixNano(0);
 }
 
 impl From<i64> for UnixNano {
@@ -85,6 +327,44 @@ impl Deref for UnixNano {
     }
 }
 
+impl Add<Duration> for UnixNano {
+    type Output = Self;
+
+    fn add(self, rhs: Duration) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub<Duration> for UnixNano {
+    type Output = Self;
+
+    fn sub(self, rhs: Duration) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Sub<UnixNano> for UnixNano {
+    type Output = Duration;
+
+    fn sub(self, rhs: UnixNano) -> Self::Output {
+        Duration(self.0 - rhs.0)
+    }
+}
+
+impl AddAssign<Duration> for UnixNano {
+    fn add_assign(&mut self, rhs: Duration) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign<Duration> for UnixNano {
+    fn sub_assign(&mut self, rhs: Duration) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl_time_val_like_nanos!(UnixNano);
+
 // `std::time::Duration` but without the u128 to u64 conversions.
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -121,15 +401,50 @@ impl Duration {
         Some(std::time::Duration::from_nanos(u64::try_from(self.0).ok()?))
     }
 
+    // Returns an `Option` because `rescale_with` does, but never `None` here:
+    // 90kHz is coarser than nanoseconds, so even `UnixNano::MAX` rescales to
+    // a value well within `i64`.
     #[must_use]
-    pub fn as_h264(&self) -> DurationH264 {
-        DurationH264::from(nano_to_timescale(self.0, H264_TIMESCALE.into()))
+    pub fn as_h264(&self) -> Option<DurationH264> {
+        rescale_with(
+            self.0,
+            NANOSECOND_TIMESCALE,
+            Timescale::new(H264_TIMESCALE),
+            Rounding::Nearest,
+        )
+        .map(DurationH264::from)
     }
 
     #[must_use]
     pub fn until(time: UnixNano) -> Option<Self> {
         Some(Self(time.checked_sub(*UnixNano::now())?))
     }
+
+    // Converts to the wire shape of `google.protobuf.Duration`.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_proto(&self) -> ProtoDuration {
+        let seconds = self.0 / SECOND;
+        let nanos = (self.0 % SECOND) as i32;
+        ProtoDuration { seconds, nanos }
+    }
+
+    // Converts from the wire shape of `google.protobuf.Duration`.
+    pub fn from_proto(duration: ProtoDuration) -> Result<Self, ProtoTimeError> {
+        if duration.nanos <= -1_000_000_000 || duration.nanos >= 1_000_000_000 {
+            return Err(ProtoTimeError::NanosOutOfRange);
+        }
+        if (duration.seconds > 0 && duration.nanos < 0)
+            || (duration.seconds < 0 && duration.nanos > 0)
+        {
+            return Err(ProtoTimeError::SignMismatch);
+        }
+        duration
+            .seconds
+            .checked_mul(SECOND)
+            .and_then(|v| v.checked_add(i64::from(duration.nanos)))
+            .map(Self)
+            .ok_or(ProtoTimeError::SecondsOutOfRange)
+    }
 }
 
 impl From<i64> for Duration {
@@ -153,29 +468,135 @@ impl Deref for Duration {
     }
 }
 
+impl Add for Duration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Duration {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul<i64> for Duration {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl AddAssign for Duration {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for Duration {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl_time_val_like_nanos!(Duration);
+
+// Wire shape of `google.protobuf.Timestamp`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProtoTimestamp {
+    pub seconds: i64,
+    pub nanos: i32,
+}
+
+// Wire shape of `google.protobuf.Duration`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProtoDuration {
+    pub seconds: i64,
+    pub nanos: i32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProtoTimeError {
+    // `seconds` falls outside the documented protobuf timestamp range, or,
+    // for a `Duration`, is too large for an `i64` nanosecond count.
+    SecondsOutOfRange,
+    // `nanos` falls outside `(-1_000_000_000, 1_000_000_000)`.
+    NanosOutOfRange,
+    // `seconds` and `nanos` have different, both non-zero, signs.
+    SignMismatch,
+    // Within the documented protobuf range, but too far from the Unix epoch
+    // to fit in an `i64` nanosecond count.
+    NotRepresentableAsNanos,
+}
+
+impl std::fmt::Display for ProtoTimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SecondsOutOfRange => write!(f, "seconds out of protobuf range"),
+            Self::NanosOutOfRange => write!(f, "nanos out of protobuf range"),
+            Self::SignMismatch => write!(f, "seconds and nanos have mismatched signs"),
+            Self::NotRepresentableAsNanos => write!(f, "timestamp not representable as nanoseconds"),
+        }
+    }
+}
+
+impl std::error::Error for ProtoTimeError {}
+
+// `google.protobuf.Timestamp.seconds` is documented to be restricted to
+// 0001-01-01T00:00:00Z through 9999-12-31T23:59:59Z.
+pub const PROTO_TIMESTAMP_SECONDS_MIN: i64 = -62_135_596_800;
+pub const PROTO_TIMESTAMP_SECONDS_MAX: i64 = 253_402_300_799;
+
+// Carries overflow from `nanos` into `seconds`, so e.g.
+// `{seconds: 1, nanos: 1_500_000_000}` becomes `{seconds: 2, nanos:
+// 500_000_000}`. Matches `google.protobuf.Timestamp`, where `nanos` is
+// always in `[0, 1_000_000_000)` regardless of the sign of `seconds`.
+#[must_use]
+pub fn normalize(mut seconds: i64, mut nanos: i32) -> (i64, i32) {
+    seconds += i64::from(nanos.div_euclid(1_000_000_000));
+    nanos = nanos.rem_euclid(1_000_000_000);
+    (seconds, nanos)
+}
+
 // The number of time units that pass per second.
 pub const H264_TIMESCALE: u32 = 90000;
 
 pub const H264_SECOND: i64 = H264_TIMESCALE as i64;
 pub const H264_MILLISECOND: i64 = H264_SECOND / 1000;
+pub const H264_MINUTE: i64 = H264_SECOND * 60;
+pub const H264_HOUR: i64 = H264_MINUTE * 60;
 
 // 90khz time since the Unix epoch.
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct UnixH264(i64);
 
 impl UnixH264 {
+    // Uses the process-wide monotonic clock, so segment/frame PTS
+    // generation never goes backwards or panics on a wall-clock jump.
     #[must_use]
     pub fn now() -> Self {
-        let nanos = i64::try_from(
-            SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .expect("time went backwards")
-                .as_nanos(),
-        )
-        .expect("timestamp to fit u64");
+        Self::now_with(MONOTONIC_CLOCK.get_or_init(MonotonicClock::new))
+    }
 
-        Self(nano_to_timescale(nanos, H264_TIMESCALE.into()))
+    // Test-injectable variant of `now()` so unit tests can drive timestamp
+    // generation deterministically instead of reading the real clock.
+    #[must_use]
+    pub fn now_with(clock: &dyn Clock) -> Self {
+        Self(
+            rescale_with(
+                *clock.now(),
+                NANOSECOND_TIMESCALE,
+                Timescale::new(H264_TIMESCALE),
+                Rounding::Nearest,
+            )
+            .expect("timestamp to fit i64"),
+        )
     }
 
     #[must_use]
@@ -193,12 +614,21 @@ impl UnixH264 {
         Some(Self(self.0.checked_sub(other.0)?))
     }
 
+    // Saturates to `UnixNano::MAX` instead of overflowing, so this stays safe
+    // to call on `UnixH264::MAX`, and clamps any negative result to zero.
+    // Truncates toward zero, like `rescale`, so it doesn't perturb
+    // non-negative in-range conversions that predate the overflow fix.
     #[must_use]
     pub fn as_nanos(&self) -> UnixNano {
-        let clock_rate = i64::from(H264_TIMESCALE);
-        let secs = self.0 / clock_rate;
-        let dec = self.0 % clock_rate;
-        UnixNano((secs * SECOND) + ((dec * SECOND) / clock_rate))
+        UnixNano(
+            rescale_saturating(
+                self.0,
+                Timescale::new(H264_TIMESCALE),
+                NANOSECOND_TIMESCALE,
+                Rounding::TowardZero,
+            )
+            .max(0),
+        )
     }
 
     // Reports whether the time intant `self` is after `other`.
@@ -215,6 +645,21 @@ impl UnixH264 {
         let nanosec = nanos % SECOND;
         NaiveDateTime::from_timestamp_opt(sec, nanosec as u32)
     }
+
+    // Adds duration, clamping to `UnixH264::MAX` instead of overflowing.
+    #[must_use]
+    pub fn saturating_add_duration(&self, duration: DurationH264) -> Self {
+        Self(self.0.saturating_add(duration.0).max(0))
+    }
+
+    // Subtracts duration, clamping to zero instead of underflowing.
+    #[must_use]
+    pub fn saturating_sub_duration(&self, duration: DurationH264) -> Self {
+        Self(self.0.saturating_sub(duration.0).max(0))
+    }
+
+    pub const MAX: UnixH264 = UnixH264(i64::MAX);
+    pub const ZERO: UnixH264 = UnixH264(0);
 }
 
 impl From<i64> for UnixH264 {
@@ -231,6 +676,44 @@ impl Deref for UnixH264 {
     }
 }
 
+impl Add<DurationH264> for UnixH264 {
+    type Output = Self;
+
+    fn add(self, rhs: DurationH264) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub<DurationH264> for UnixH264 {
+    type Output = Self;
+
+    fn sub(self, rhs: DurationH264) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Sub<UnixH264> for UnixH264 {
+    type Output = DurationH264;
+
+    fn sub(self, rhs: UnixH264) -> Self::Output {
+        DurationH264(self.0 - rhs.0)
+    }
+}
+
+impl AddAssign<DurationH264> for UnixH264 {
+    fn add_assign(&mut self, rhs: DurationH264) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign<DurationH264> for UnixH264 {
+    fn sub_assign(&mut self, rhs: DurationH264) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl_time_val_like_h264!(UnixH264, this, *this.as_nanos());
+
 // H264 duration with 90khz timescale.
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -294,13 +777,13 @@ impl DurationH264 {
         self.as_nanos() / MILLISECOND
     }
 
+    // Saturates to `i64::MAX`/`i64::MIN` instead of overflowing, so this
+    // stays safe to call on e.g. `DurationH264::from(i64::MAX)`. Truncates
+    // toward zero, like `rescale`, so it doesn't perturb in-range
+    // conversions that predate the overflow fix.
     #[must_use]
-    #[allow(clippy::cast_precision_loss)]
     pub fn as_nanos(&self) -> i64 {
-        let clock_rate = i64::from(H264_TIMESCALE);
-        let secs = self.0 / clock_rate;
-        let dec = self.0 % clock_rate;
-        (secs * SECOND) + ((dec * SECOND) / clock_rate)
+        rescale_saturating(self.0, Timescale::new(H264_TIMESCALE), NANOSECOND_TIMESCALE, Rounding::TowardZero)
     }
 }
 
@@ -336,12 +819,166 @@ impl Deref for DurationH264 {
     }
 }
 
+impl Add for DurationH264 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for DurationH264 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul<i64> for DurationH264 {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl AddAssign for DurationH264 {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for DurationH264 {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl_time_val_like_h264!(DurationH264, this, this.as_nanos());
+
+// The number of clock units that pass per second, e.g. 90000 for the H264
+// clock or 48000 for 48kHz audio. Lets a single conversion function serve
+// any track instead of hardcoding `H264_TIMESCALE`. `0` is accepted (e.g. a
+// track whose sample rate hasn't been parsed yet); `rescale`/`rescale_with`
+// treat it as "not yet convertible" instead of panicking.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timescale(u32);
+
+impl Timescale {
+    #[must_use]
+    pub const fn new(v: u32) -> Self {
+        Self(v)
+    }
+
+    #[must_use]
+    pub const fn get(&self) -> u32 {
+        self.0
+    }
+}
+
+impl From<u32> for Timescale {
+    fn from(v: u32) -> Self {
+        Self(v)
+    }
+}
+
+// Nanoseconds are just another timescale (1e9 units per second), so
+// `nano_to_timescale` is a thin wrapper around the generic conversion.
+pub const NANOSECOND_TIMESCALE: Timescale = Timescale(1_000_000_000);
+pub const MICROSECOND_TIMESCALE: Timescale = Timescale(1_000_000);
+
+// Converts value from one timescale into another, keeping each track in its
+// native clock rate and only rescaling at container boundaries. Returns `0`
+// instead of panicking if `from` is `Timescale(0)` (e.g. an unconfigured
+// track's rate).
+#[must_use]
+pub fn rescale(value: i64, from: Timescale, to: Timescale) -> i64 {
+    if from.0 == 0 {
+        return 0;
+    }
+    let from = i64::from(from.0);
+    let to = i64::from(to.0);
+    let secs = value / from;
+    let dec = value % from;
+    (secs * to) + (dec * to / from)
+}
+
+// How the fractional remainder of a rescale is rounded to a whole tick of
+// the target timescale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    // Truncate, like `rescale`/`nano_to_timescale`.
+    TowardZero,
+    // Round to the closest tick, ties away from zero.
+    Nearest,
+    // Round toward positive infinity.
+    Up,
+    // Round toward negative infinity.
+    Down,
+}
+
+// Same as `rescale`, but widens the remainder product to `i128` before
+// dividing so it can't overflow for large timescales, supports selectable
+// rounding, and reports overflow of the final `i64` result instead of
+// truncating it. This is what keeps accumulated rounding drift from causing
+// A/V desync over long recordings. Returns `None`, instead of panicking, if
+// `from` is `Timescale(0)` (e.g. an unconfigured track's rate).
+#[must_use]
+pub fn rescale_with(value: i64, from: Timescale, to: Timescale, rounding: Rounding) -> Option<i64> {
+    if from.0 == 0 {
+        return None;
+    }
+    let from = i64::from(from.0);
+    let to = i64::from(to.0);
+    let secs = value / from;
+    let dec = value % from;
+
+    let numerator = i128::from(dec) * i128::from(to);
+    let from = i128::from(from);
+    let dec_scaled = match rounding {
+        Rounding::TowardZero => numerator / from,
+        Rounding::Nearest => {
+            let half = from / 2;
+            if numerator >= 0 {
+                (numerator + half) / from
+            } else {
+                (numerator - half) / from
+            }
+        }
+        Rounding::Up => {
+            if numerator > 0 && numerator % from != 0 {
+                numerator / from + 1
+            } else {
+                numerator / from
+            }
+        }
+        Rounding::Down => {
+            if numerator < 0 && numerator % from != 0 {
+                numerator / from - 1
+            } else {
+                numerator / from
+            }
+        }
+    };
+
+    let secs_scaled = i128::from(secs) * i128::from(to);
+    i64::try_from(secs_scaled + dec_scaled).ok()
+}
+
 // Converts value in nanoseconds into a different timescale.
 #[must_use]
 pub fn nano_to_timescale(value: i64, timescale: i64) -> i64 {
-    let secs = value / SECOND;
-    let dec = value % SECOND;
-    (secs * timescale) + (dec * timescale / SECOND)
+    rescale(value, NANOSECOND_TIMESCALE, Timescale(u32::try_from(timescale).unwrap_or(u32::MAX)))
+}
+
+// Like `rescale_with`, but saturates to `i64::MAX`/`i64::MIN` on overflow
+// instead of returning `None`. Used by accessors whose signature predates
+// `rescale_with` (e.g. `as_nanos()`) and so can't propagate an `Option`.
+#[must_use]
+pub fn rescale_saturating(value: i64, from: Timescale, to: Timescale, rounding: Rounding) -> i64 {
+    rescale_with(value, from, to, rounding).unwrap_or(if value < 0 { i64::MIN } else { i64::MAX })
 }
 
 #[cfg(test)]
@@ -359,4 +996,203 @@ mod tests {
     fn test_nano_to_timescale(input: i64, scale: i64, want: i64) {
         assert_eq!(want, nano_to_timescale(input, scale));
     }
+
+    #[test_case(1_000_000_000, Timescale::new(48000), 48_000; "one_second_48khz")]
+    #[test_case(1_000_000_000, Timescale::new(44100), 44_100; "one_second_44_1khz")]
+    #[test_case(1_000_000_000, Timescale::new(H264_TIMESCALE), 90_000; "one_second_h264")]
+    fn test_rescale(input: i64, to: Timescale, want: i64) {
+        assert_eq!(want, rescale(input, NANOSECOND_TIMESCALE, to));
+    }
+
+    #[test_case(1, Timescale::new(48000), Rounding::TowardZero, Some(0); "truncates_toward_zero")]
+    #[test_case(1, Timescale::new(48000), Rounding::Nearest, Some(0); "rounds_to_nearest_down")]
+    #[test_case(15_000, Timescale::new(48000), Rounding::Nearest, Some(1); "rounds_to_nearest_up")]
+    #[test_case(1, Timescale::new(48000), Rounding::Up, Some(1); "rounds_up")]
+    #[test_case(1, Timescale::new(48000), Rounding::Down, Some(0); "rounds_down")]
+    fn test_rescale_with(input: i64, to: Timescale, rounding: Rounding, want: Option<i64>) {
+        assert_eq!(want, rescale_with(input, NANOSECOND_TIMESCALE, to, rounding));
+    }
+
+    #[test]
+    fn test_rescale_with_overflow() {
+        assert_eq!(
+            None,
+            rescale_with(i64::MAX, NANOSECOND_TIMESCALE, Timescale::new(u32::MAX), Rounding::Nearest)
+        );
+    }
+
+    #[test]
+    fn test_rescale_zero_from_timescale_returns_zero_instead_of_panicking() {
+        assert_eq!(0, rescale(1_000_000_000, Timescale::new(0), NANOSECOND_TIMESCALE));
+    }
+
+    #[test]
+    fn test_rescale_with_zero_from_timescale_returns_none_instead_of_panicking() {
+        assert_eq!(
+            None,
+            rescale_with(1_000_000_000, Timescale::new(0), NANOSECOND_TIMESCALE, Rounding::TowardZero)
+        );
+    }
+
+    #[test_case(1, 1_500_000_000, 2, 500_000_000; "carries_overflow")]
+    #[test_case(1, 0, 1, 0; "already_normal")]
+    #[test_case(1, -500_000_000, 0, 500_000_000; "carries_negative_nanos")]
+    fn test_normalize(seconds: i64, nanos: i32, want_seconds: i64, want_nanos: i32) {
+        assert_eq!((want_seconds, want_nanos), normalize(seconds, nanos));
+    }
+
+    #[test]
+    fn test_unix_nano_proto_roundtrip() {
+        let want = UnixNano::from(1_234_567_890_123_456_789);
+        let proto = want.to_proto().unwrap();
+        assert_eq!(UnixNano::from_proto(proto).unwrap(), want);
+    }
+
+    #[test]
+    fn test_unix_nano_proto_seconds_out_of_range() {
+        let proto = ProtoTimestamp { seconds: PROTO_TIMESTAMP_SECONDS_MAX + 1, nanos: 0 };
+        assert_eq!(Err(ProtoTimeError::SecondsOutOfRange), UnixNano::from_proto(proto));
+    }
+
+    #[test]
+    fn test_unix_nano_proto_not_representable_as_nanos() {
+        // Within the documented protobuf range (up to year 9999), but far
+        // too large to fit in an `i64` nanosecond count.
+        let proto = ProtoTimestamp { seconds: PROTO_TIMESTAMP_SECONDS_MAX, nanos: 0 };
+        assert_eq!(Err(ProtoTimeError::NotRepresentableAsNanos), UnixNano::from_proto(proto));
+    }
+
+    #[test]
+    fn test_duration_proto_roundtrip() {
+        let want = Duration::from_nanos(-1_500_000_000);
+        let proto = want.to_proto();
+        assert_eq!(ProtoDuration { seconds: -1, nanos: -500_000_000 }, proto);
+        assert_eq!(Duration::from_proto(proto).unwrap(), want);
+    }
+
+    #[test]
+    fn test_duration_proto_sign_mismatch() {
+        let proto = ProtoDuration { seconds: 1, nanos: -1 };
+        assert_eq!(Err(ProtoTimeError::SignMismatch), Duration::from_proto(proto));
+    }
+
+    #[test]
+    fn test_duration_h264_unit_constructors() {
+        assert_eq!(H264_HOUR, *DurationH264::hours(1));
+        assert_eq!(H264_MINUTE, *DurationH264::minutes(1));
+        assert_eq!(H264_SECOND, *DurationH264::seconds(1));
+        assert_eq!(H264_MILLISECOND, *DurationH264::milliseconds(1));
+        assert_eq!(1, DurationH264::seconds(1).num_seconds());
+        assert_eq!(1_000_000_000, DurationH264::seconds(1).num_nanoseconds());
+    }
+
+    #[test]
+    fn test_time_val_like_saturates_on_overflow() {
+        assert_eq!(i64::MAX, *Duration::hours(i64::MAX));
+        assert_eq!(i64::MAX, *DurationH264::hours(i64::MAX));
+        assert_eq!(i64::MIN, *Duration::hours(i64::MIN));
+    }
+
+    #[test]
+    fn test_unix_nano_operators() {
+        let start = UnixNano::from(0);
+        let range = start..=start + Duration::from_hours(1);
+        assert!(range.contains(&UnixNano::from(HOUR)));
+        assert!(!range.contains(&UnixNano::from(HOUR + 1)));
+
+        let mut t = start;
+        t += Duration::from_hours(1);
+        assert_eq!(UnixNano::from(HOUR), t);
+        assert_eq!(Duration::from_hours(1), t - start);
+    }
+
+    #[test]
+    fn test_unix_nano_saturating_add_sub() {
+        assert_eq!(UnixNano::MAX, UnixNano::MAX.saturating_add_duration(Duration::from_hours(1)));
+        assert_eq!(UnixNano::ZERO, UnixNano::ZERO.saturating_sub_duration(Duration::from_hours(1)));
+    }
+
+    #[test]
+    fn test_duration_h264_operators() {
+        let mut d = DurationH264::seconds(1);
+        d += DurationH264::seconds(1);
+        assert_eq!(DurationH264::seconds(2), d);
+        assert_eq!(DurationH264::seconds(6), DurationH264::seconds(2) * 3);
+        assert!(DurationH264::seconds(1) < DurationH264::seconds(2));
+    }
+
+    struct FakeClock(UnixNano);
+
+    impl Clock for FakeClock {
+        fn now(&self) -> UnixNano {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_unix_h264_now_with_fake_clock() {
+        let clock = FakeClock(UnixNano::from(SECOND));
+        assert_eq!(DurationH264::seconds(1), DurationH264::from(UnixH264::now_with(&clock)));
+    }
+
+    #[test]
+    fn test_wall_clock_matches_system_time() {
+        let before = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+        let got = *WallClock.now();
+        let after = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+        assert!(i128::from(got) >= i128::try_from(before).unwrap());
+        assert!(i128::from(got) <= i128::try_from(after).unwrap());
+    }
+
+    #[test]
+    fn test_monotonic_clock_advances_with_instant() {
+        let clock = MonotonicClock::new();
+        let first = clock.now();
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        let second = clock.now();
+        assert!(second.after(first));
+    }
+
+    #[test]
+    fn test_as_nanos_saturates_instead_of_overflowing() {
+        assert_eq!(UnixNano::MAX, UnixH264::from(i64::MAX).as_nanos());
+        assert_eq!(i64::MAX, DurationH264::from(i64::MAX).as_nanos());
+    }
+
+    #[test]
+    fn test_as_nanos_truncates_toward_zero_for_in_range_values() {
+        // Picked so the exact result (1_499_988_888.89ns) has a fraction
+        // above one half, where `Rounding::Nearest` would round up and
+        // disagree with `rescale`'s truncation.
+        let ticks = i64::from(H264_TIMESCALE) + 44999;
+        let want = rescale(ticks, Timescale::new(H264_TIMESCALE), NANOSECOND_TIMESCALE);
+        assert_eq!(UnixNano::from(want), UnixH264::from(ticks).as_nanos());
+        assert_eq!(want, DurationH264::from(ticks).as_nanos());
+    }
+
+    #[test]
+    fn test_num_microseconds_saturates_instead_of_overflowing() {
+        assert_eq!(i64::MAX, UnixH264::from(i64::MAX).num_microseconds());
+        assert_eq!(i64::MAX, DurationH264::from(i64::MAX).num_microseconds());
+    }
+
+    #[test]
+    fn test_num_microseconds_truncates_toward_zero_for_in_range_values() {
+        // Same divergence-inducing remainder as
+        // `test_as_nanos_truncates_toward_zero_for_in_range_values`.
+        let ticks = i64::from(H264_TIMESCALE) + 44999;
+        let want = rescale(ticks, Timescale::new(H264_TIMESCALE), MICROSECOND_TIMESCALE);
+        assert_eq!(want, UnixH264::from(ticks).num_microseconds());
+        assert_eq!(want, DurationH264::from(ticks).num_microseconds());
+    }
+
+    #[test]
+    fn test_h264_unit_accessors_agree_on_truncation() {
+        // `num_seconds` and `num_nanoseconds`/`1_000_000_000` must agree on
+        // the same instant, since both are `TimeValLike` accessors on the
+        // same type and should all truncate toward zero consistently.
+        let ticks = i64::from(H264_TIMESCALE) + 44999;
+        let h264 = UnixH264::from(ticks);
+        assert_eq!(h264.num_seconds(), h264.num_nanoseconds() / 1_000_000_000);
+    }
 }